@@ -1,27 +1,204 @@
 use if_addrs::get_if_addrs;
-use libmdns::Responder;
+use libmdns::{Responder, Service};
 use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
 use std::env;
+use std::fs;
 use std::io;
+use std::io::Read as _;
 use std::net::IpAddr;
 use std::time::Duration;
+use tokio::sync::mpsc;
 
 const DEFAULT_POLL_SECONDS: u64 = 3;
+const NETLINK_DEBOUNCE_MILLIS: u64 = 200;
 
 #[derive(Debug, Clone)]
 struct CliOptions {
     name: String,
     interfaces: Vec<String>,
+    hooks: Vec<(HookEvent, String)>,
+    services: Vec<ServiceSpec>,
+    config_path: Option<String>,
+    format: OutputFormat,
+    state_file: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            other => Err(format!("unknown --format value '{other}', expected text or json")),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct StatusEvent<'a> {
+    timestamp: u64,
+    event: &'a str,
+    hostname: &'a str,
+    interfaces: String,
+    visible_ips: &'a [IpAddr],
+}
+
+fn unix_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+fn emit_status_event(event: &str, hostname: &str, filter: &InterfaceFilter, visible_ips: &[IpAddr]) {
+    let status = StatusEvent {
+        timestamp: unix_timestamp(),
+        event,
+        hostname,
+        interfaces: filter.as_log_value(),
+        visible_ips,
+    };
+    match serde_json::to_string(&status) {
+        Ok(line) => println!("{line}"),
+        Err(err) => warn!("failed to serialize status event: {err}"),
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct DaemonState<'a> {
+    hostname: &'a str,
+    interfaces: &'a [InterfaceSnapshot],
+    services: &'a [ServiceSpec],
+}
+
+fn write_state_file(
+    path: &str,
+    hostname: &str,
+    snapshot: &[InterfaceSnapshot],
+    services: &[ServiceSpec],
+) -> io::Result<()> {
+    let state = DaemonState {
+        hostname,
+        interfaces: snapshot,
+        services,
+    };
+    let json = serde_json::to_string_pretty(&state).map_err(io::Error::other)?;
+
+    let tmp_path = format!("{path}.tmp");
+    fs::write(&tmp_path, json)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    include: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+}
+
+fn load_config(path: &str) -> Result<ConfigFile, String> {
+    let contents = if path == "-" {
+        let mut buf = String::new();
+        io::stdin()
+            .read_to_string(&mut buf)
+            .map_err(|err| format!("failed to read config from stdin: {err}"))?;
+        buf
+    } else {
+        fs::read_to_string(path)
+            .map_err(|err| format!("failed to read config file '{path}': {err}"))?
+    };
+
+    toml::from_str(&contents)
+        .map_err(|err| format!("failed to parse config file '{path}': {err}"))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct ServiceSpec {
+    service_type: String,
+    instance_name: String,
+    port: u16,
+    txt: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ServiceSpecBuilder {
+    service_type: String,
+    instance_name: Option<String>,
+    port: Option<u16>,
+    txt: Vec<String>,
+}
+
+impl ServiceSpecBuilder {
+    fn finish(self) -> Result<ServiceSpec, String> {
+        let instance_name = self.instance_name.ok_or_else(|| {
+            format!(
+                "--service {} is missing a --instance <name>",
+                self.service_type
+            )
+        })?;
+        let port = self
+            .port
+            .ok_or_else(|| format!("--service {} is missing a --port <port>", self.service_type))?;
+        Ok(ServiceSpec {
+            service_type: self.service_type,
+            instance_name,
+            port,
+            txt: self.txt,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HookEvent {
+    Startup,
+    InterfaceAdded,
+    InterfaceRemoved,
+    ResponderRestart,
+    Shutdown,
+}
+
+impl HookEvent {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "startup" => Some(Self::Startup),
+            "interface-added" => Some(Self::InterfaceAdded),
+            "interface-removed" => Some(Self::InterfaceRemoved),
+            "responder-restart" => Some(Self::ResponderRestart),
+            "shutdown" => Some(Self::Shutdown),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Startup => "startup",
+            Self::InterfaceAdded => "interface-added",
+            Self::InterfaceRemoved => "interface-removed",
+            Self::ResponderRestart => "responder-restart",
+            Self::Shutdown => "shutdown",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 enum InterfaceFilter {
     All,
-    Only(BTreeSet<String>),
+    Patterns {
+        include: Vec<String>,
+        exclude: Vec<String>,
+    },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 struct InterfaceSnapshot {
     name: String,
     ip: IpAddr,
@@ -34,7 +211,7 @@ impl InterfaceFilter {
             return Self::All;
         }
 
-        let mut selected = BTreeSet::new();
+        let mut include = Vec::new();
         for value in values {
             for item in value.split(',') {
                 let iface = item.trim();
@@ -44,35 +221,105 @@ impl InterfaceFilter {
                 if iface == "*" {
                     return Self::All;
                 }
-                selected.insert(iface.to_owned());
+                include.push(iface.to_owned());
             }
         }
 
-        if selected.is_empty() {
+        if include.is_empty() {
             Self::All
         } else {
-            Self::Only(selected)
+            Self::Patterns {
+                include,
+                exclude: Vec::new(),
+            }
+        }
+    }
+
+    fn merge_config(self, config: &ConfigFile) -> Self {
+        match self {
+            Self::All => {
+                if config.include.is_empty() && config.exclude.is_empty() {
+                    Self::All
+                } else {
+                    Self::Patterns {
+                        include: config.include.clone(),
+                        exclude: config.exclude.clone(),
+                    }
+                }
+            }
+            Self::Patterns { include, mut exclude } => {
+                exclude.extend(config.exclude.iter().cloned());
+                Self::Patterns { include, exclude }
+            }
         }
     }
 
     fn matches(&self, iface_name: &str) -> bool {
         match self {
             Self::All => true,
-            Self::Only(only) => only.contains(iface_name),
+            Self::Patterns { include, exclude } => {
+                let included = include.is_empty()
+                    || include.iter().any(|pattern| glob_match(pattern, iface_name));
+                let excluded = exclude.iter().any(|pattern| glob_match(pattern, iface_name));
+                included && !excluded
+            }
+        }
+    }
+
+    fn literal_includes(&self) -> BTreeSet<String> {
+        match self {
+            Self::All => BTreeSet::new(),
+            Self::Patterns { include, .. } => include
+                .iter()
+                .filter(|pattern| !pattern.contains(['*', '?']))
+                .cloned()
+                .collect(),
         }
     }
 
     fn as_log_value(&self) -> String {
         match self {
             Self::All => "*".to_owned(),
-            Self::Only(only) => only.iter().cloned().collect::<Vec<_>>().join(","),
+            Self::Patterns { include, exclude } => {
+                let mut parts = include.clone();
+                parts.extend(exclude.iter().map(|pattern| format!("!{pattern}")));
+                parts.join(",")
+            }
+        }
+    }
+}
+
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0usize, 0usize);
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, t));
+        } else {
+            return false;
         }
     }
+
+    while p < pattern.len() && pattern[p] == '*' {
+        p += 1;
+    }
+    p == pattern.len()
 }
 
 fn print_usage(program: &str) {
     println!(
-        "Usage:\n  {program} --name <name> [--interface <iface> ...]\n  {program} <name> [--interface <iface> ...]\n\nOptions:\n  -n, --name <name>           Host name, resolves as <name>.local\n  -i, --interface <iface>     Interface name, repeatable. Default is '*' (all)\n  -h, --help                  Show this help"
+        "Usage:\n  {program} --name <name> [--interface <iface> ...]\n  {program} <name> [--interface <iface> ...]\n\nOptions:\n  -n, --name <name>           Host name, resolves as <name>.local\n  -i, --interface <iface>     Interface name, repeatable. Default is '*' (all)\n      --hook <event>:<cmd>    Run <cmd> on <event>, repeatable. event is one of\n                              startup, interface-added, interface-removed,\n                              responder-restart, shutdown\n      --service <type>       Advertise a DNS-SD service, e.g. _http._tcp.\n                              Followed by --instance/--port and optional\n                              --txt for that service. Repeatable.\n      --instance <name>      Instance name for the preceding --service\n      --port <port>          Port for the preceding --service\n      --txt <key=value>      TXT record entry for the preceding --service,\n                              repeatable\n      --config <path>        TOML config with interface include/exclude\n                              globs, '-' reads from stdin. CLI flags win.\n      --format <fmt>         Output format: text (default) or json\n      --state-file <path>    Write current advertised state as JSON here\n                              on every change\n  -h, --help                  Show this help"
     );
 }
 
@@ -82,6 +329,12 @@ fn parse_args() -> Result<CliOptions, String> {
 
     let mut name: Option<String> = None;
     let mut interfaces = Vec::new();
+    let mut hooks = Vec::new();
+    let mut services = Vec::new();
+    let mut pending_service: Option<ServiceSpecBuilder> = None;
+    let mut config_path: Option<String> = None;
+    let mut format = OutputFormat::Text;
+    let mut state_file: Option<String> = None;
     let mut positional = Vec::new();
     let mut i = 1usize;
 
@@ -120,6 +373,127 @@ fn parse_args() -> Result<CliOptions, String> {
                 }
                 interfaces.push(value.to_owned());
             }
+            "--hook" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("missing value after --hook".to_owned());
+                }
+                hooks.push(parse_hook(&args[i])?);
+            }
+            _ if arg.starts_with("--hook=") => {
+                let value = arg.trim_start_matches("--hook=");
+                hooks.push(parse_hook(value)?);
+            }
+            "--service" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("missing value after --service".to_owned());
+                }
+                if let Some(builder) = pending_service.take() {
+                    services.push(builder.finish()?);
+                }
+                pending_service = Some(ServiceSpecBuilder {
+                    service_type: args[i].clone(),
+                    ..Default::default()
+                });
+            }
+            _ if arg.starts_with("--service=") => {
+                let value = arg.trim_start_matches("--service=");
+                if let Some(builder) = pending_service.take() {
+                    services.push(builder.finish()?);
+                }
+                pending_service = Some(ServiceSpecBuilder {
+                    service_type: value.to_owned(),
+                    ..Default::default()
+                });
+            }
+            "--instance" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("missing value after --instance".to_owned());
+                }
+                let builder = pending_service
+                    .as_mut()
+                    .ok_or_else(|| "--instance must follow a --service".to_owned())?;
+                builder.instance_name = Some(args[i].clone());
+            }
+            _ if arg.starts_with("--instance=") => {
+                let value = arg.trim_start_matches("--instance=");
+                let builder = pending_service
+                    .as_mut()
+                    .ok_or_else(|| "--instance must follow a --service".to_owned())?;
+                builder.instance_name = Some(value.to_owned());
+            }
+            "--port" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("missing value after --port".to_owned());
+                }
+                let port: u16 = args[i]
+                    .parse()
+                    .map_err(|_| format!("invalid --port value '{}'", args[i]))?;
+                let builder = pending_service
+                    .as_mut()
+                    .ok_or_else(|| "--port must follow a --service".to_owned())?;
+                builder.port = Some(port);
+            }
+            _ if arg.starts_with("--port=") => {
+                let value = arg.trim_start_matches("--port=");
+                let port: u16 = value
+                    .parse()
+                    .map_err(|_| format!("invalid --port value '{value}'"))?;
+                let builder = pending_service
+                    .as_mut()
+                    .ok_or_else(|| "--port must follow a --service".to_owned())?;
+                builder.port = Some(port);
+            }
+            "--txt" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("missing value after --txt".to_owned());
+                }
+                let builder = pending_service
+                    .as_mut()
+                    .ok_or_else(|| "--txt must follow a --service".to_owned())?;
+                builder.txt.push(args[i].clone());
+            }
+            _ if arg.starts_with("--txt=") => {
+                let value = arg.trim_start_matches("--txt=");
+                let builder = pending_service
+                    .as_mut()
+                    .ok_or_else(|| "--txt must follow a --service".to_owned())?;
+                builder.txt.push(value.to_owned());
+            }
+            "--config" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("missing value after --config".to_owned());
+                }
+                config_path = Some(args[i].clone());
+            }
+            _ if arg.starts_with("--config=") => {
+                config_path = Some(arg.trim_start_matches("--config=").to_owned());
+            }
+            "--format" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("missing value after --format".to_owned());
+                }
+                format = OutputFormat::parse(&args[i])?;
+            }
+            _ if arg.starts_with("--format=") => {
+                format = OutputFormat::parse(arg.trim_start_matches("--format="))?;
+            }
+            "--state-file" => {
+                i += 1;
+                if i >= args.len() {
+                    return Err("missing value after --state-file".to_owned());
+                }
+                state_file = Some(args[i].clone());
+            }
+            _ if arg.starts_with("--state-file=") => {
+                state_file = Some(arg.trim_start_matches("--state-file=").to_owned());
+            }
             _ if arg.starts_with('-') => return Err(format!("unknown option: {arg}")),
             _ => positional.push(arg.clone()),
         }
@@ -159,7 +533,42 @@ fn parse_args() -> Result<CliOptions, String> {
         interfaces.push("*".to_owned());
     }
 
-    Ok(CliOptions { name, interfaces })
+    if let Some(builder) = pending_service.take() {
+        services.push(builder.finish()?);
+    }
+
+    Ok(CliOptions {
+        name,
+        interfaces,
+        hooks,
+        services,
+        config_path,
+        format,
+        state_file,
+    })
+}
+
+fn build_filter(options: &CliOptions) -> Result<InterfaceFilter, String> {
+    let filter = InterfaceFilter::from_values(&options.interfaces);
+    match &options.config_path {
+        Some(path) => {
+            let config = load_config(path)?;
+            Ok(filter.merge_config(&config))
+        }
+        None => Ok(filter),
+    }
+}
+
+fn parse_hook(value: &str) -> Result<(HookEvent, String), String> {
+    let (event, command) = value
+        .split_once(':')
+        .ok_or_else(|| format!("invalid --hook value '{value}', expected <event>:<command>"))?;
+    let event = HookEvent::parse(event)
+        .ok_or_else(|| format!("unknown hook event '{event}' in --hook"))?;
+    if command.is_empty() {
+        return Err(format!("empty command in --hook={value}"));
+    }
+    Ok((event, command.to_owned()))
 }
 
 fn collect_snapshot(filter: &InterfaceFilter) -> io::Result<Vec<InterfaceSnapshot>> {
@@ -184,9 +593,10 @@ fn collect_snapshot(filter: &InterfaceFilter) -> io::Result<Vec<InterfaceSnapsho
 }
 
 fn collect_missing_interfaces(filter: &InterfaceFilter) -> io::Result<Vec<String>> {
-    let InterfaceFilter::Only(selected) = filter else {
+    let selected = filter.literal_includes();
+    if selected.is_empty() {
         return Ok(Vec::new());
-    };
+    }
 
     let existing: BTreeSet<String> = get_if_addrs()?
         .into_iter()
@@ -223,24 +633,350 @@ fn start_responder(
     name: &str,
     filter: &InterfaceFilter,
     snapshot: &[InterfaceSnapshot],
-) -> io::Result<Responder> {
+    services: &[ServiceSpec],
+    format: OutputFormat,
+    event: &str,
+) -> io::Result<(Responder, Vec<Service>)> {
     let allowed_ips = selected_ips(filter, snapshot);
     let mut display_ips: Vec<IpAddr> = snapshot.iter().map(|item| item.ip).collect();
     display_ips.sort();
     display_ips.dedup();
 
-    info!(
-        "starting mdns responder: hostname={}, interfaces={}, visible_ips={:?}",
-        fqdn_name(name),
-        filter.as_log_value(),
-        display_ips
-    );
+    let fqdn = fqdn_name(name);
+    match format {
+        OutputFormat::Text => {
+            info!(
+                "starting mdns responder: hostname={fqdn}, interfaces={}, visible_ips={display_ips:?}",
+                filter.as_log_value(),
+            );
+        }
+        OutputFormat::Json => emit_status_event(event, &fqdn, filter, &display_ips),
+    }
     debug!("responder allowed_ips={allowed_ips:?}");
 
     let (responder, task) =
         Responder::with_default_handle_and_ip_list_and_hostname(allowed_ips, name.to_owned())?;
     tokio::spawn(task);
-    Ok(responder)
+
+    let registered = services
+        .iter()
+        .map(|service| {
+            info!(
+                "registering service: type={}, instance={}, port={}",
+                service.service_type, service.instance_name, service.port
+            );
+            let txt: Vec<&str> = service.txt.iter().map(String::as_str).collect();
+            responder.register(
+                service.service_type.clone(),
+                service.instance_name.clone(),
+                service.port,
+                &txt,
+            )
+        })
+        .collect();
+
+    Ok((responder, registered))
+}
+
+#[cfg(target_os = "linux")]
+mod netlink_watch {
+    use futures::stream::StreamExt;
+    use netlink_packet_core::NetlinkPayload;
+    use netlink_packet_route::RouteNetlinkMessage;
+    use netlink_sys::{AsyncSocket, SocketAddr};
+    use rtnetlink::constants::{RTMGRP_IPV4_IFADDR, RTMGRP_IPV6_IFADDR, RTMGRP_LINK};
+    use std::io;
+    use tokio::sync::mpsc;
+    use tokio::time::{Duration, Instant};
+
+    use super::NETLINK_DEBOUNCE_MILLIS;
+
+    pub fn spawn() -> io::Result<mpsc::UnboundedReceiver<()>> {
+        let (mut connection, _handle, mut messages) = rtnetlink::new_connection()?;
+
+        let groups = RTMGRP_LINK | RTMGRP_IPV4_IFADDR | RTMGRP_IPV6_IFADDR;
+        connection
+            .socket_mut()
+            .socket_mut()
+            .bind(&SocketAddr::new(0, groups))?;
+        tokio::spawn(connection);
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let debounce = Duration::from_millis(NETLINK_DEBOUNCE_MILLIS);
+            let sleep = tokio::time::sleep(debounce);
+            tokio::pin!(sleep);
+            let mut pending = false;
+
+            loop {
+                tokio::select! {
+                    next = messages.next() => {
+                        let Some((message, _)) = next else {
+                            break;
+                        };
+                        if is_relevant(&message.payload) {
+                            pending = true;
+                            sleep.as_mut().reset(Instant::now() + debounce);
+                        }
+                    }
+                    () = &mut sleep, if pending => {
+                        pending = false;
+                        if tx.send(()).is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+
+    fn is_relevant(payload: &NetlinkPayload<RouteNetlinkMessage>) -> bool {
+        matches!(
+            payload,
+            NetlinkPayload::InnerMessage(
+                RouteNetlinkMessage::NewLink(_)
+                    | RouteNetlinkMessage::DelLink(_)
+                    | RouteNetlinkMessage::NewAddress(_)
+                    | RouteNetlinkMessage::DelAddress(_)
+            )
+        )
+    }
+}
+
+fn diff_ips(old: &[InterfaceSnapshot], new: &[InterfaceSnapshot]) -> (Vec<IpAddr>, Vec<IpAddr>) {
+    let old_ips: BTreeSet<IpAddr> = old.iter().map(|item| item.ip).collect();
+    let new_ips: BTreeSet<IpAddr> = new.iter().map(|item| item.ip).collect();
+
+    let added = new_ips.difference(&old_ips).copied().collect();
+    let removed = old_ips.difference(&new_ips).copied().collect();
+    (added, removed)
+}
+
+fn join_ips(ips: &[IpAddr]) -> String {
+    ips.iter()
+        .map(|ip| ip.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+async fn run_hooks(
+    hooks: &[(HookEvent, String)],
+    event: HookEvent,
+    fqdn: &str,
+    filter: &InterfaceFilter,
+    added: &[IpAddr],
+    removed: &[IpAddr],
+) {
+    for (hook_event, command) in hooks {
+        if *hook_event != event {
+            continue;
+        }
+
+        debug!("running {} hook: {command}", event.as_str());
+        let result = tokio::process::Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .env("MICROMDNS_HOSTNAME", fqdn)
+            .env("MICROMDNS_EVENT", event.as_str())
+            .env("MICROMDNS_INTERFACES", filter.as_log_value())
+            .env("MICROMDNS_ADDED_IPS", join_ips(added))
+            .env("MICROMDNS_REMOVED_IPS", join_ips(removed))
+            .status()
+            .await;
+
+        match result {
+            Ok(status) if !status.success() => {
+                warn!("{} hook exited with {status}: {command}", event.as_str());
+            }
+            Ok(_) => {}
+            Err(err) => {
+                warn!("failed to run {} hook '{command}': {err}", event.as_str());
+            }
+        }
+    }
+}
+
+struct IpChanges<'a> {
+    added: &'a [IpAddr],
+    removed: &'a [IpAddr],
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn restart_responder_with_hooks(
+    name: &str,
+    filter: &InterfaceFilter,
+    snapshot: &mut Vec<InterfaceSnapshot>,
+    responder: &mut Option<(Responder, Vec<Service>)>,
+    services: &[ServiceSpec],
+    hooks: &[(HookEvent, String)],
+    format: OutputFormat,
+    state_file: Option<&str>,
+    fqdn: &str,
+    changes: IpChanges<'_>,
+    restarted_log: Option<&str>,
+    failed_log: &str,
+) {
+    let IpChanges { added, removed } = changes;
+
+    if let Some(old) = responder.take() {
+        drop(old);
+    }
+
+    if !added.is_empty() {
+        run_hooks(hooks, HookEvent::InterfaceAdded, fqdn, filter, added, &[]).await;
+    }
+    if !removed.is_empty() {
+        run_hooks(hooks, HookEvent::InterfaceRemoved, fqdn, filter, &[], removed).await;
+    }
+
+    match start_responder(name, filter, snapshot, services, format, "restart") {
+        Ok(new_responder) => {
+            *responder = Some(new_responder);
+            if let Some(msg) = restarted_log {
+                info!("{msg}");
+            }
+            run_hooks(hooks, HookEvent::ResponderRestart, fqdn, filter, added, removed).await;
+
+            if let Some(path) = state_file {
+                if let Err(err) = write_state_file(path, fqdn, snapshot, services) {
+                    warn!("failed to write state file '{path}': {err}");
+                }
+            }
+        }
+        Err(err) => error!("{failed_log}: {err}"),
+    }
+}
+
+async fn refresh_and_maybe_restart(
+    name: &str,
+    filter: &InterfaceFilter,
+    snapshot: &mut Vec<InterfaceSnapshot>,
+    responder: &mut Option<(Responder, Vec<Service>)>,
+    services: &[ServiceSpec],
+    hooks: &[(HookEvent, String)],
+    format: OutputFormat,
+    state_file: Option<&str>,
+) {
+    let current_snapshot = match collect_snapshot(filter) {
+        Ok(value) => value,
+        Err(err) => {
+            warn!("failed to refresh interface list: {err}");
+            return;
+        }
+    };
+
+    if &current_snapshot == snapshot {
+        return;
+    }
+
+    debug!("old_snapshot={snapshot:?}");
+    debug!("new_snapshot={current_snapshot:?}");
+    let (added, removed) = diff_ips(snapshot, &current_snapshot);
+    *snapshot = current_snapshot;
+
+    let fqdn = fqdn_name(name);
+    let mut display_ips: Vec<IpAddr> = snapshot.iter().map(|item| item.ip).collect();
+    display_ips.sort();
+    display_ips.dedup();
+    match format {
+        OutputFormat::Text => info!("network interface change detected, restarting mdns responder"),
+        OutputFormat::Json => emit_status_event("interface-change", &fqdn, filter, &display_ips),
+    }
+
+    let restarted_log = (format == OutputFormat::Text).then_some("mdns responder restarted");
+    restart_responder_with_hooks(
+        name,
+        filter,
+        snapshot,
+        responder,
+        services,
+        hooks,
+        format,
+        state_file,
+        &fqdn,
+        IpChanges {
+            added: &added,
+            removed: &removed,
+        },
+        restarted_log,
+        "failed to restart mdns responder",
+    )
+    .await;
+}
+
+#[cfg(unix)]
+async fn handle_sighup(
+    options: &mut CliOptions,
+    filter: &mut InterfaceFilter,
+    snapshot: &mut Vec<InterfaceSnapshot>,
+    responder: &mut Option<(Responder, Vec<Service>)>,
+    hooks: &[(HookEvent, String)],
+    state_file: Option<&str>,
+) {
+    info!("received SIGHUP, reloading configuration");
+
+    let new_options = match parse_args() {
+        Ok(value) => value,
+        Err(err) => {
+            warn!("failed to reload arguments, keeping current configuration: {err}");
+            return;
+        }
+    };
+    let new_filter = match build_filter(&new_options) {
+        Ok(value) => value,
+        Err(err) => {
+            warn!("failed to reload config, keeping current configuration: {err}");
+            return;
+        }
+    };
+    let current_snapshot = match collect_snapshot(&new_filter) {
+        Ok(value) => value,
+        Err(err) => {
+            warn!("failed to refresh interface list during reload: {err}");
+            return;
+        }
+    };
+
+    let old_ips = selected_ips(filter, snapshot);
+    let new_ips = selected_ips(&new_filter, &current_snapshot);
+    let changed =
+        new_options.name != options.name || new_options.services != options.services || old_ips != new_ips;
+
+    if !changed {
+        *options = new_options;
+        *filter = new_filter;
+        *snapshot = current_snapshot;
+        info!("reload: no effective change, keeping current responder");
+        return;
+    }
+
+    let (added, removed) = diff_ips(snapshot, &current_snapshot);
+    *options = new_options;
+    *filter = new_filter;
+    *snapshot = current_snapshot;
+
+    info!("reload: configuration changed, restarting mdns responder");
+    let fqdn = fqdn_name(&options.name);
+    restart_responder_with_hooks(
+        &options.name,
+        filter,
+        snapshot,
+        responder,
+        &options.services,
+        hooks,
+        options.format,
+        state_file,
+        &fqdn,
+        IpChanges {
+            added: &added,
+            removed: &removed,
+        },
+        Some("mdns responder restarted after reload"),
+        "failed to restart mdns responder after reload",
+    )
+    .await;
 }
 
 fn init_logger() {
@@ -257,7 +993,7 @@ fn init_logger() {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     init_logger();
 
-    let options = match parse_args() {
+    let mut options = match parse_args() {
         Ok(value) => value,
         Err(err) => {
             eprintln!("argument error: {err}");
@@ -267,7 +1003,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
 
-    let filter = InterfaceFilter::from_values(&options.interfaces);
+    let mut filter = match build_filter(&options) {
+        Ok(value) => value,
+        Err(err) => {
+            eprintln!("config error: {err}");
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, err).into());
+        }
+    };
     info!(
         "config loaded: name={}, interfaces={}",
         options.name,
@@ -285,46 +1027,101 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     debug!("initial interface snapshot={snapshot:?}");
 
-    let mut responder = Some(start_responder(&options.name, &filter, &snapshot)?);
+    let mut responder = Some(start_responder(
+        &options.name,
+        &filter,
+        &snapshot,
+        &options.services,
+        options.format,
+        "startup",
+    )?);
+    let fqdn = fqdn_name(&options.name);
+    run_hooks(
+        &options.hooks,
+        HookEvent::Startup,
+        &fqdn,
+        &filter,
+        &snapshot.iter().map(|item| item.ip).collect::<Vec<_>>(),
+        &[],
+    )
+    .await;
+    if let Some(path) = &options.state_file {
+        if let Err(err) = write_state_file(path, &fqdn, &snapshot, &options.services) {
+            warn!("failed to write state file '{path}': {err}");
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    let mut netlink_rx = match netlink_watch::spawn() {
+        Ok(rx) => {
+            info!("watching interface changes via netlink");
+            Some(rx)
+        }
+        Err(err) => {
+            warn!("failed to open netlink socket ({err}), falling back to polling");
+            None
+        }
+    };
+    #[cfg(not(target_os = "linux"))]
+    let mut netlink_rx: Option<mpsc::UnboundedReceiver<()>> = None;
 
     let mut ticker = tokio::time::interval(Duration::from_secs(DEFAULT_POLL_SECONDS));
     ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
 
+    #[cfg(unix)]
+    let mut hangup = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())?;
+
     loop {
         tokio::select! {
             _ = tokio::signal::ctrl_c() => {
                 info!("received Ctrl+C, shutting down");
                 break;
             }
-            _ = ticker.tick() => {
-                let current_snapshot = match collect_snapshot(&filter) {
-                    Ok(value) => value,
-                    Err(err) => {
-                        warn!("failed to refresh interface list: {err}");
-                        continue;
-                    }
-                };
-
-                if current_snapshot != snapshot {
-                    info!("network interface change detected, restarting mdns responder");
-                    debug!("old_snapshot={snapshot:?}");
-                    debug!("new_snapshot={current_snapshot:?}");
-                    snapshot = current_snapshot;
-
-                    if let Some(old) = responder.take() {
-                        drop(old);
-                    }
-
-                    match start_responder(&options.name, &filter, &snapshot) {
-                        Ok(new_responder) => {
-                            responder = Some(new_responder);
-                            info!("mdns responder restarted");
-                        }
-                        Err(err) => {
-                            error!("failed to restart mdns responder: {err}");
-                        }
-                    }
+            #[cfg(unix)]
+            _ = hangup.recv() => {
+                let reload_hooks = options.hooks.clone();
+                let reload_state_file = options.state_file.clone();
+                handle_sighup(
+                    &mut options,
+                    &mut filter,
+                    &mut snapshot,
+                    &mut responder,
+                    &reload_hooks,
+                    reload_state_file.as_deref(),
+                )
+                .await;
+            }
+            signal = async { netlink_rx.as_mut().unwrap().recv().await }, if netlink_rx.is_some() => {
+                if signal.is_none() {
+                    warn!("netlink watcher exited, falling back to polling");
+                    netlink_rx = None;
+                    continue;
                 }
+
+                refresh_and_maybe_restart(
+                    &options.name,
+                    &filter,
+                    &mut snapshot,
+                    &mut responder,
+                    &options.services,
+                    &options.hooks,
+                    options.format,
+                    options.state_file.as_deref(),
+                )
+                .await;
+            }
+            _ = ticker.tick(), if netlink_rx.is_none() => {
+                refresh_and_maybe_restart(
+                    &options.name,
+                    &filter,
+                    &mut snapshot,
+                    &mut responder,
+                    &options.services,
+                    &options.hooks,
+                    options.format,
+                    options.state_file.as_deref(),
+                )
+                .await;
             }
         }
     }
@@ -332,6 +1129,208 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     if let Some(active) = responder.take() {
         drop(active);
     }
+    let fqdn = fqdn_name(&options.name);
+    if options.format == OutputFormat::Json {
+        emit_status_event("shutdown", &fqdn, &filter, &[]);
+    }
+    run_hooks(&options.hooks, HookEvent::Shutdown, &fqdn, &filter, &[], &[]).await;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_match_literal() {
+        assert!(glob_match("eth0", "eth0"));
+        assert!(!glob_match("eth0", "eth1"));
+    }
+
+    #[test]
+    fn glob_match_star() {
+        assert!(glob_match("wg*", "wg0"));
+        assert!(glob_match("wg*", "wg"));
+        assert!(glob_match("*docker0", "br-docker0"));
+        assert!(!glob_match("wg*", "eth0"));
+    }
+
+    #[test]
+    fn glob_match_question_mark() {
+        assert!(glob_match("eth?", "eth0"));
+        assert!(!glob_match("eth?", "eth10"));
+    }
+
+    #[test]
+    fn glob_match_no_pattern_metachars_requires_exact_match() {
+        assert!(!glob_match("eth0", "eth00"));
+        assert!(!glob_match("eth00", "eth0"));
+    }
+
+    #[test]
+    fn glob_match_empty_pattern_only_matches_empty_text() {
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "eth0"));
+    }
+
+    #[test]
+    fn interface_filter_all_matches_everything() {
+        assert!(InterfaceFilter::All.matches("eth0"));
+        assert!(InterfaceFilter::All.matches("anything"));
+    }
+
+    #[test]
+    fn interface_filter_include_and_exclude() {
+        let filter = InterfaceFilter::Patterns {
+            include: vec!["wg*".to_owned()],
+            exclude: vec!["wg9".to_owned()],
+        };
+        assert!(filter.matches("wg0"));
+        assert!(!filter.matches("wg9"));
+        assert!(!filter.matches("eth0"));
+    }
+
+    #[test]
+    fn interface_filter_empty_include_means_everything_included() {
+        let filter = InterfaceFilter::Patterns {
+            include: Vec::new(),
+            exclude: vec!["docker0".to_owned()],
+        };
+        assert!(filter.matches("eth0"));
+        assert!(!filter.matches("docker0"));
+    }
+
+    #[test]
+    fn from_values_star_is_all() {
+        assert!(matches!(
+            InterfaceFilter::from_values(&["*".to_owned()]),
+            InterfaceFilter::All
+        ));
+    }
+
+    #[test]
+    fn from_values_empty_is_all() {
+        assert!(matches!(InterfaceFilter::from_values(&[]), InterfaceFilter::All));
+    }
+
+    #[test]
+    fn from_values_splits_commas_into_include_patterns() {
+        let filter = InterfaceFilter::from_values(&["eth0,wg*".to_owned()]);
+        assert!(filter.matches("eth0"));
+        assert!(filter.matches("wg0"));
+        assert!(!filter.matches("eth1"));
+    }
+
+    #[test]
+    fn merge_config_fills_in_all_filter() {
+        let config = ConfigFile {
+            include: vec!["wg*".to_owned()],
+            exclude: vec!["wg9".to_owned()],
+        };
+        let filter = InterfaceFilter::All.merge_config(&config);
+        assert!(filter.matches("wg0"));
+        assert!(!filter.matches("wg9"));
+        assert!(!filter.matches("eth0"));
+    }
+
+    #[test]
+    fn merge_config_cli_include_wins_but_config_exclude_still_applies() {
+        let cli_filter = InterfaceFilter::from_values(&["eth0".to_owned()]);
+        let config = ConfigFile {
+            include: vec!["wg*".to_owned()],
+            exclude: vec!["eth0".to_owned()],
+        };
+        let filter = cli_filter.merge_config(&config);
+        // CLI include wins: wg* from the config is not honored.
+        assert!(!filter.matches("wg0"));
+        // But the config's exclude still takes effect even though the CLI
+        // picked the include list.
+        assert!(!filter.matches("eth0"));
+    }
+
+    #[test]
+    fn diff_ips_reports_added_and_removed() {
+        let old = vec![InterfaceSnapshot {
+            name: "eth0".to_owned(),
+            ip: "10.0.0.1".parse().unwrap(),
+            index: Some(1),
+        }];
+        let new = vec![InterfaceSnapshot {
+            name: "eth0".to_owned(),
+            ip: "10.0.0.2".parse().unwrap(),
+            index: Some(1),
+        }];
+        let (added, removed) = diff_ips(&old, &new);
+        assert_eq!(added, vec!["10.0.0.2".parse::<IpAddr>().unwrap()]);
+        assert_eq!(removed, vec!["10.0.0.1".parse::<IpAddr>().unwrap()]);
+    }
+
+    #[test]
+    fn parse_hook_valid() {
+        let (event, command) = parse_hook("startup:echo hi").unwrap();
+        assert_eq!(event, HookEvent::Startup);
+        assert_eq!(command, "echo hi");
+    }
+
+    #[test]
+    fn parse_hook_missing_colon() {
+        assert!(parse_hook("startup").is_err());
+    }
+
+    #[test]
+    fn parse_hook_unknown_event() {
+        assert!(parse_hook("nonexistent:echo hi").is_err());
+    }
+
+    #[test]
+    fn parse_hook_empty_command() {
+        assert!(parse_hook("startup:").is_err());
+    }
+
+    #[test]
+    fn service_spec_builder_finish_ok() {
+        let builder = ServiceSpecBuilder {
+            service_type: "_http._tcp".to_owned(),
+            instance_name: Some("printer".to_owned()),
+            port: Some(631),
+            txt: vec!["path=/".to_owned()],
+        };
+        let spec = builder.finish().unwrap();
+        assert_eq!(spec.service_type, "_http._tcp");
+        assert_eq!(spec.instance_name, "printer");
+        assert_eq!(spec.port, 631);
+        assert_eq!(spec.txt, vec!["path=/".to_owned()]);
+    }
+
+    #[test]
+    fn service_spec_builder_finish_missing_instance() {
+        let builder = ServiceSpecBuilder {
+            service_type: "_http._tcp".to_owned(),
+            port: Some(631),
+            ..Default::default()
+        };
+        assert!(builder.finish().is_err());
+    }
+
+    #[test]
+    fn service_spec_builder_finish_missing_port() {
+        let builder = ServiceSpecBuilder {
+            service_type: "_http._tcp".to_owned(),
+            instance_name: Some("printer".to_owned()),
+            ..Default::default()
+        };
+        assert!(builder.finish().is_err());
+    }
+
+    #[test]
+    fn output_format_parse_valid() {
+        assert_eq!(OutputFormat::parse("text").unwrap(), OutputFormat::Text);
+        assert_eq!(OutputFormat::parse("json").unwrap(), OutputFormat::Json);
+    }
+
+    #[test]
+    fn output_format_parse_invalid() {
+        assert!(OutputFormat::parse("yaml").is_err());
+    }
+}